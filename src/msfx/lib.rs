@@ -1,10 +1,20 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// `Entry::Object`/`JSON` and the `alloc`-backed string (de)serialization methods need
+// an allocator even when `std` is unavailable (e.g. embedded or WASI targets).
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
 #[path = "db/types.rs"]
 pub mod types;
 
-// Declare the `util/json.rs` module directly
+// Declare the `util/json.rs` module directly. Only built with the `alloc` feature,
+// since `JSON`/`Entry` store their fields in a heap-allocated map.
+#[cfg(feature = "alloc")]
 #[path = "util/json.rs"]
 pub mod json;
 
 // Re-export items from `types` and `json` modules
 pub use types::Types;
-pub use json::{JSON, Entry};
\ No newline at end of file
+#[cfg(feature = "alloc")]
+pub use json::{JSON, Entry, Schema, TimestampMode};
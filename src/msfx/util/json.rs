@@ -3,25 +3,47 @@
 //! The `JSON` struct can hold key-value pairs where values are of type `Entry`,
 //! supporting various data types, including booleans, decimals, dates, times, and binary data.
 
+use crate::types::Types;
 use base64::{engine::general_purpose::STANDARD, Engine};
-use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, SecondsFormat, TimeZone, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::{self, Value};
-use std::collections::HashMap;
+
+// Under `std`, `Entry::Object`/`JSON` are backed by a `HashMap`; under `no_std` there is
+// no hasher without pulling in a third-party one, so `alloc::collections::BTreeMap`
+// (ordered by `String`'s `Ord` impl) serves as the `alloc`-only backing store instead.
+#[cfg(feature = "std")]
+use std::collections::HashMap as Map;
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap as Map;
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::str::FromStr;
+#[cfg(not(feature = "std"))]
+use core::str::FromStr;
 
 /// Represents a flexible data entry that can hold various data types.
 ///
-/// `Entry` allows storage of booleans, decimals, dates, times, timestamps, strings,
-/// binary data, and nested objects.#[derive(Debug, Clone, PartialEq)]
+/// `Entry` allows storage of booleans, decimals, integers, longs, floats, dates, times,
+/// timestamps, strings, binary data, and nested objects.
+#[derive(Debug, Clone, PartialEq)]
 pub enum Entry {
     Boolean(bool),
     Decimal(Decimal),
+    Integer(i64),
+    Long(i64),
+    Float(f64),
     Date(NaiveDate),
     Time(NaiveTime),
     Timestamp(DateTime<Utc>),
     String(String),
-    Object(HashMap<String, Entry>),
+    Object(Map<String, Entry>),
     Binary(Vec<u8>), // New variant to store binary data
 }
 
@@ -31,17 +53,156 @@ impl<'de> Deserialize<'de> for Entry {
         D: Deserializer<'de>,
     {
         let value = Value::deserialize(deserializer)?;
+        Entry::from_value_with_mode(value, TimestampMode::Rfc3339)
+            .map_err(|e| serde::de::Error::custom(e.to_string()))
+    }
+}
+
+/// Governs how `Entry::Timestamp` is read from and written to JSON numbers, mirroring
+/// chrono's `ts_seconds`/`ts_milliseconds`/`ts_microseconds` (de)serialization helpers.
+///
+/// Under `Rfc3339` (the default) timestamps round-trip through RFC3339 strings and JSON
+/// numbers are read as plain numeric entries. Under an epoch mode, every JSON number that
+/// fits an `i64` is instead read as a count since the Unix epoch (other numbers are still
+/// read as plain numeric entries) and `Entry::Timestamp` is written back out the same way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimestampMode {
+    #[default]
+    Rfc3339,
+    EpochSeconds,
+    EpochMillis,
+    EpochMicros,
+}
+
+impl TimestampMode {
+    /// Interprets a JSON number as an epoch timestamp under this mode, or returns `None`
+    /// under `Rfc3339` (or for a number that does not fit an `i64`) so the caller falls
+    /// back to `entry_from_number`.
+    fn decode_number(&self, num: &serde_json::Number) -> Option<Result<Entry, serde_json::Error>> {
+        let (count, whole_secs, nanos_per_unit): (i64, _, u32) = match self {
+            TimestampMode::Rfc3339 => return None,
+            TimestampMode::EpochSeconds => (num.as_i64()?, 1, 0),
+            TimestampMode::EpochMillis => (num.as_i64()?, 1_000, 1_000_000),
+            TimestampMode::EpochMicros => (num.as_i64()?, 1_000_000, 1_000),
+        };
+        let secs = count.div_euclid(whole_secs);
+        let remainder = count.rem_euclid(whole_secs);
+        let nanos = remainder as u32 * nanos_per_unit;
+
+        Some(
+            Utc.timestamp_opt(secs, nanos)
+                .single()
+                .map(Entry::Timestamp)
+                .ok_or_else(|| serde::de::Error::custom(format!("Epoch value out of range: {}", count))),
+        )
+    }
+
+    /// Encodes a timestamp as this mode dictates: an RFC3339 string formatted per
+    /// `seconds_format`/`use_z`, or the integer count since the Unix epoch.
+    fn encode_timestamp(
+        &self,
+        timestamp: &DateTime<Utc>,
+        seconds_format: SecondsFormat,
+        use_z: bool,
+    ) -> Value {
+        match self {
+            TimestampMode::Rfc3339 => {
+                Value::String(timestamp.to_rfc3339_opts(seconds_format, use_z))
+            }
+            TimestampMode::EpochSeconds => Value::from(timestamp.timestamp()),
+            TimestampMode::EpochMillis => Value::from(timestamp.timestamp_millis()),
+            TimestampMode::EpochMicros => Value::from(timestamp.timestamp_micros()),
+        }
+    }
+}
+
+/// Converts a JSON number into the narrowest fitting `Entry` variant: `Entry::Integer`
+/// for values in `i32` range, `Entry::Long` for other `i64` values, and `Entry::Float`
+/// for anything else that parses as an `f64` (fractional values, and integers beyond the
+/// `u64` range). Integers above `i64::MAX` that fit a `u64` are rejected with an error.
+/// `Entry::Decimal` is never produced here —
+/// it is only used when a field is explicitly typed `Types::DECIMAL` via a `Schema`.
+fn entry_from_number(num: &serde_json::Number) -> Result<Entry, serde_json::Error> {
+    if let Some(n) = num.as_i64() {
+        if i32::try_from(n).is_ok() {
+            Ok(Entry::Integer(n))
+        } else {
+            Ok(Entry::Long(n))
+        }
+    } else if let Some(n) = num.as_u64() {
+        i64::try_from(n)
+            .map(Entry::Long)
+            .map_err(|_| serde::de::Error::custom(format!("Integer out of range: {}", n)))
+    } else if let Some(f) = num.as_f64() {
+        Ok(Entry::Float(f))
+    } else {
+        Err(serde::de::Error::custom("Invalid number type"))
+    }
+}
+
+/// Parses `s` as a timestamp, accepting a few spellings beyond strict RFC3339 so that
+/// `dt.to_string().parse()`-style round-trips and common SQL/log output survive:
+/// a space instead of `T` between date and time, and bare (offset-less) datetimes,
+/// which are interpreted as UTC.
+fn parse_timestamp(s: &str) -> Option<DateTime<Utc>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Some(dt.with_timezone(&Utc));
+    }
+
+    if s.as_bytes().get(10) == Some(&b' ') {
+        let mut with_t = s.to_string();
+        with_t.replace_range(10..11, "T");
+        if let Ok(dt) = DateTime::parse_from_rfc3339(&with_t) {
+            return Some(dt.with_timezone(&Utc));
+        }
+    }
 
+    for fmt in ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"] {
+        if let Ok(naive) = NaiveDateTime::parse_from_str(s, fmt) {
+            return Some(Utc.from_utc_datetime(&naive));
+        }
+    }
+
+    None
+}
+
+impl Entry {
+    /// Returns the `Types` that this `Entry` variant maps back to, used by
+    /// [`JSON::validate`] and anywhere a declared schema type needs checking.
+    pub fn type_of(&self) -> Types {
+        match self {
+            Entry::Boolean(_) => Types::BOOLEAN,
+            Entry::Decimal(_) => Types::DECIMAL,
+            Entry::Integer(_) => Types::INTEGER,
+            Entry::Long(_) => Types::LONG,
+            Entry::Float(_) => Types::FLOAT,
+            Entry::Date(_) => Types::DATE,
+            Entry::Time(_) => Types::TIME,
+            Entry::Timestamp(_) => Types::TIMESTAMP,
+            Entry::String(_) => Types::VARCHAR,
+            Entry::Object(_) => Types::JSON,
+            Entry::Binary(_) => Types::VARBINARY,
+        }
+    }
+
+    /// Deserializes a single JSON `value` into an `Entry`, reading JSON numbers as
+    /// epoch timestamps instead of plain numbers when `mode` is not `TimestampMode::Rfc3339`.
+    ///
+    /// `mode` only governs numbers at this level, not ones nested inside `Value::Object`:
+    /// without a `Schema` to say which fields are actually timestamps, applying an epoch
+    /// mode recursively would reinterpret every unrelated nested integer (an id, a count,
+    /// a quantity) as a date. Nested objects are therefore always read with
+    /// `TimestampMode::Rfc3339`, matching best-effort `deserialize`. No other entry point
+    /// reads epoch numbers either: a `Types::TIMESTAMP` field in a `Schema` accepts only
+    /// timestamp strings.
+    fn from_value_with_mode(value: Value, mode: TimestampMode) -> Result<Self, serde_json::Error> {
         let entry = match value {
             Value::Bool(b) => Entry::Boolean(b),
             Value::Number(num) => {
-                if let Some(f) = num.as_f64() {
-                    match Decimal::try_from(f) {
-                        Ok(decimal) => Entry::Decimal(decimal),
-                        Err(_) => return Err(serde::de::Error::custom("Invalid decimal type")),
-                    }
+                if let Some(result) = mode.decode_number(&num) {
+                    result?
                 } else {
-                    return Err(serde::de::Error::custom("Invalid number type"));
+                    entry_from_number(&num)?
                 }
             }
             Value::String(s) => {
@@ -51,8 +212,8 @@ impl<'de> Deserialize<'de> for Entry {
                     Entry::Date(date)
                 } else if let Ok(time) = NaiveTime::parse_from_str(&s, "%H:%M:%S") {
                     Entry::Time(time)
-                } else if let Ok(timestamp) = DateTime::parse_from_rfc3339(&s) {
-                    Entry::Timestamp(timestamp.with_timezone(&Utc))
+                } else if let Some(timestamp) = parse_timestamp(&s) {
+                    Entry::Timestamp(timestamp)
                 } else {
                     Entry::String(s)
                 }
@@ -61,10 +222,10 @@ impl<'de> Deserialize<'de> for Entry {
                 let object = map
                     .into_iter()
                     .map(|(k, v)| {
-                        let entry = serde_json::from_value(v).map_err(serde::de::Error::custom)?;
+                        let entry = Entry::from_value_with_mode(v, TimestampMode::Rfc3339)?;
                         Ok((k, entry))
                     })
-                    .collect::<Result<HashMap<_, _>, _>>()?;
+                    .collect::<Result<Map<_, _>, _>>()?;
                 Entry::Object(object)
             }
             _ => return Err(serde::de::Error::custom("Unsupported JSON type")),
@@ -72,6 +233,161 @@ impl<'de> Deserialize<'de> for Entry {
 
         Ok(entry)
     }
+
+    /// Converts this `Entry` into a `serde_json::Value`, writing `Entry::Timestamp`
+    /// through `mode`, `seconds_format` and `use_z` instead of always emitting a
+    /// full-precision RFC3339 string.
+    fn to_value_with_mode(
+        &self,
+        mode: TimestampMode,
+        seconds_format: SecondsFormat,
+        use_z: bool,
+    ) -> Value {
+        match self {
+            Entry::Boolean(b) => Value::Bool(*b),
+            Entry::Decimal(d) => Value::String(d.to_string()),
+            Entry::Integer(i) => Value::from(*i),
+            Entry::Long(l) => Value::from(*l),
+            Entry::Float(f) => Value::from(*f),
+            Entry::Date(date) => Value::String(date.format("%Y-%m-%d").to_string()),
+            Entry::Time(time) => Value::String(time.format("%H:%M:%S").to_string()),
+            Entry::Timestamp(timestamp) => mode.encode_timestamp(timestamp, seconds_format, use_z),
+            Entry::String(s) => Value::String(s.clone()),
+            Entry::Binary(data) => Value::String(STANDARD.encode(data)),
+            Entry::Object(map) => Value::Object(
+                map.iter()
+                    .map(|(k, v)| (k.clone(), v.to_value_with_mode(mode, seconds_format, use_z)))
+                    .collect(),
+            ),
+        }
+    }
+    /// Deserializes a single JSON `value` into the `Entry` variant mandated by `ty`,
+    /// erroring out instead of guessing when the value does not match the declared type.
+    ///
+    /// `nested` supplies the sub-`Schema` to recurse with when `ty` is `Types::JSON`;
+    /// without it, fields of the nested object fall back to the best-effort `deserialize`.
+    fn from_value_typed(
+        value: Value,
+        ty: &Types,
+        nested: Option<&Schema>,
+    ) -> Result<Self, serde_json::Error> {
+        match ty {
+            Types::VARCHAR | Types::CLOB => match value {
+                Value::String(s) => Ok(Entry::String(s)),
+                _ => Err(serde::de::Error::custom(format!(
+                    "Expected a string value for type {}",
+                    ty
+                ))),
+            },
+            Types::VARBINARY | Types::BLOB => match value {
+                Value::String(s) => STANDARD
+                    .decode(&s)
+                    .map(Entry::Binary)
+                    .map_err(|e| serde::de::Error::custom(format!("Invalid base64 data: {}", e))),
+                _ => Err(serde::de::Error::custom(format!(
+                    "Expected a base64-encoded string for type {}",
+                    ty
+                ))),
+            },
+            Types::DATE => match value {
+                Value::String(s) => NaiveDate::parse_from_str(&s, "%Y-%m-%d")
+                    .map(Entry::Date)
+                    .map_err(|e| serde::de::Error::custom(format!("Invalid date '{}': {}", s, e))),
+                _ => Err(serde::de::Error::custom("Expected a date string")),
+            },
+            Types::TIME => match value {
+                Value::String(s) => NaiveTime::parse_from_str(&s, "%H:%M:%S")
+                    .map(Entry::Time)
+                    .map_err(|e| serde::de::Error::custom(format!("Invalid time '{}': {}", s, e))),
+                _ => Err(serde::de::Error::custom("Expected a time string")),
+            },
+            Types::TIMESTAMP => match &value {
+                Value::String(s) => parse_timestamp(s).map(Entry::Timestamp).ok_or_else(|| {
+                    serde::de::Error::custom(format!("Invalid timestamp '{}'", s))
+                }),
+                _ => Err(serde::de::Error::custom("Expected a timestamp string")),
+            },
+            Types::JSON => match value {
+                Value::Object(map) => {
+                    let entries = map
+                        .into_iter()
+                        .map(|(k, v)| {
+                            let entry = match nested.and_then(|s| s.type_of(&k)) {
+                                Some(sub_ty) => Entry::from_value_typed(
+                                    v,
+                                    sub_ty,
+                                    nested.and_then(|s| s.schema_of(&k)),
+                                )?,
+                                None => serde_json::from_value(v).map_err(serde::de::Error::custom)?,
+                            };
+                            Ok((k, entry))
+                        })
+                        .collect::<Result<Map<_, _>, _>>()?;
+                    Ok(Entry::Object(entries))
+                }
+                _ => Err(serde::de::Error::custom("Expected a JSON object")),
+            },
+            Types::BOOLEAN => match value {
+                Value::Bool(b) => Ok(Entry::Boolean(b)),
+                _ => Err(serde::de::Error::custom("Expected a boolean value for type BOOLEAN")),
+            },
+            // Without the `arbitrary_precision` serde_json feature, a fractional `Number`
+            // is already backed by an `f64` by the time it reaches us, so parsing its
+            // `to_string()` output would merely relocate the precision loss rather than
+            // avoid it. `arbitrary_precision` keeps the original JSON token around instead,
+            // and `as_str()` hands it back verbatim for `Decimal::from_str` to parse exactly.
+            Types::DECIMAL => match &value {
+                Value::Number(num) => Decimal::from_str(num.as_str())
+                    .map(Entry::Decimal)
+                    .map_err(|e| {
+                        serde::de::Error::custom(format!("Invalid decimal value '{}': {}", num, e))
+                    }),
+                _ => Err(serde::de::Error::custom("Expected a numeric value for type DECIMAL")),
+            },
+            Types::FLOAT => match &value {
+                Value::Number(num) => num
+                    .as_f64()
+                    .map(Entry::Float)
+                    .ok_or_else(|| serde::de::Error::custom("Invalid number for type FLOAT")),
+                _ => Err(serde::de::Error::custom("Expected a numeric value for type FLOAT")),
+            },
+            Types::INTEGER => match &value {
+                Value::Number(num) => num
+                    .as_i64()
+                    .filter(|n| i32::try_from(*n).is_ok())
+                    .map(Entry::Integer)
+                    .ok_or_else(|| {
+                        serde::de::Error::custom("Expected an i32-range integer for type INTEGER")
+                    }),
+                _ => Err(serde::de::Error::custom("Expected a numeric value for type INTEGER")),
+            },
+            Types::LONG => match &value {
+                Value::Number(num) => num
+                    .as_i64()
+                    .map(Entry::Long)
+                    .ok_or_else(|| serde::de::Error::custom("Expected an integer value for type LONG")),
+                _ => Err(serde::de::Error::custom("Expected a numeric value for type LONG")),
+            },
+        }
+    }
+
+    /// Reports whether this entry is a value that `ty` could plausibly have produced,
+    /// used by [`JSON::validate`] to check a document against a `Schema`.
+    ///
+    /// Besides exact matches, a numeric `Entry` also matches a wider declared numeric
+    /// type: an `Entry::Integer` (which untyped `deserialize` already produces for any
+    /// `i32`-range whole number) satisfies `Types::LONG`, and both `Entry::Integer` and
+    /// `Entry::Long` satisfy `Types::FLOAT`, even though an `Entry::Long` beyond 2^53 has
+    /// no exact `f64` equivalent.
+    fn matches_type(&self, ty: &Types) -> bool {
+        match (self, ty) {
+            (Entry::String(_), Types::VARCHAR | Types::CLOB) => true,
+            (Entry::Binary(_), Types::VARBINARY | Types::BLOB) => true,
+            (Entry::Integer(_), Types::LONG) => true,
+            (Entry::Integer(_) | Entry::Long(_), Types::FLOAT) => true,
+            _ => self.type_of() == *ty,
+        }
+    }
 }
 
 impl Serialize for Entry {
@@ -79,37 +395,101 @@ impl Serialize for Entry {
     where
         S: Serializer,
     {
-        match self {
-            Entry::Boolean(b) => serializer.serialize_bool(*b),
-            Entry::Decimal(d) => serializer.serialize_str(&d.to_string()),
-            Entry::Date(date) => serializer.serialize_str(&date.format("%Y-%m-%d").to_string()),
-            Entry::Time(time) => serializer.serialize_str(&time.format("%H:%M:%S").to_string()),
-            Entry::Timestamp(timestamp) => serializer.serialize_str(&timestamp.to_rfc3339()),
-            Entry::String(s) => serializer.serialize_str(s),
-            Entry::Binary(data) => {
-                let encoded = STANDARD.encode(data);
-                serializer.serialize_str(&encoded)
-            }
-            Entry::Object(map) => map.serialize(serializer),
-        }
+        self.to_value_with_mode(TimestampMode::Rfc3339, SecondsFormat::AutoSi, false)
+            .serialize(serializer)
+    }
+}
+
+/// Declares the expected `Types` of a document's fields so that deserialization can
+/// bind values to `Entry` variants directly instead of guessing from the JSON shape.
+///
+/// A field typed `Types::JSON` may carry a nested `Schema` (see [`Schema::nested`]) so
+/// that `deserialize_with_schema` and `validate` recurse into the sub-object as well.
+#[derive(Debug, Clone, Default)]
+pub struct Schema {
+    fields: Map<String, Types>,
+    nested: Map<String, Schema>,
+}
+
+impl Schema {
+    pub fn new() -> Self {
+        Schema::default()
+    }
+
+    /// Declares `name` as having the given `Types`.
+    pub fn field(mut self, name: impl Into<String>, ty: Types) -> Self {
+        self.fields.insert(name.into(), ty);
+        self
+    }
+
+    /// Declares `name` as a `Types::JSON` field governed by `schema`.
+    pub fn nested(mut self, name: impl Into<String>, schema: Schema) -> Self {
+        let name = name.into();
+        self.fields.insert(name.clone(), Types::JSON);
+        self.nested.insert(name, schema);
+        self
+    }
+
+    /// Returns the declared `Types` of `name`, if any.
+    pub fn type_of(&self, name: &str) -> Option<&Types> {
+        self.fields.get(name)
+    }
+
+    /// Returns the sub-`Schema` of `name`, if it was declared with [`Schema::nested`].
+    pub fn schema_of(&self, name: &str) -> Option<&Schema> {
+        self.nested.get(name)
     }
 }
 
 /// Represents a JSON-like object that stores key-value pairs where values are of type `Entry`.
 ///
 /// The `JSON` struct provides methods to serialize, deserialize, and manage data with
-/// multiple possible types, as represented by the `Entry` enum.#[derive(Debug, Clone)]
+/// multiple possible types, as represented by the `Entry` enum.
+#[derive(Debug, Clone)]
 pub struct JSON {
-    entries: HashMap<String, Entry>,
+    entries: Map<String, Entry>,
+    timestamp_mode: TimestampMode,
+    timestamp_seconds_format: SecondsFormat,
+    timestamp_use_z: bool,
+}
+
+impl Default for JSON {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl JSON {
     pub fn new() -> Self {
         JSON {
-            entries: HashMap::new(),
+            entries: Map::new(),
+            timestamp_mode: TimestampMode::default(),
+            timestamp_seconds_format: SecondsFormat::AutoSi,
+            timestamp_use_z: false,
         }
     }
 
+    /// Sets the `TimestampMode` that `serialize` uses to write `Entry::Timestamp` values.
+    /// Deserialization does not read it; `deserialize_with_mode` takes its own `mode`.
+    pub fn with_timestamp_mode(mut self, mode: TimestampMode) -> Self {
+        self.timestamp_mode = mode;
+        self
+    }
+
+    /// Sets the sub-second precision used when serializing `Entry::Timestamp` under
+    /// `TimestampMode::Rfc3339` (ignored under the epoch modes).
+    pub fn with_seconds_format(mut self, format: SecondsFormat) -> Self {
+        self.timestamp_seconds_format = format;
+        self
+    }
+
+    /// When `true`, serializes UTC `Entry::Timestamp` values under `TimestampMode::Rfc3339`
+    /// with a trailing `Z` instead of `+00:00`.
+    pub fn with_use_z(mut self, use_z: bool) -> Self {
+        self.timestamp_use_z = use_z;
+        self
+    }
+
     /// Deserializes a JSON string into a `JSON` object.
     ///
     /// # Arguments
@@ -120,17 +500,131 @@ impl JSON {
     ///
     /// Returns an error if the JSON string cannot be parsed.
     pub fn deserialize(json_str: &str) -> Result<Self, serde_json::Error> {
-        let entries: HashMap<String, Entry> = serde_json::from_str(json_str)?;
-        Ok(JSON { entries })
+        let entries: Map<String, Entry> = serde_json::from_str(json_str)?;
+        Ok(JSON {
+            entries,
+            timestamp_mode: TimestampMode::default(),
+            timestamp_seconds_format: SecondsFormat::AutoSi,
+            timestamp_use_z: false,
+        })
+    }
+
+    /// Deserializes a JSON string into a `JSON` object, reading top-level JSON numbers as
+    /// epoch timestamps rather than plain numbers when `mode` is not `TimestampMode::Rfc3339`.
+    /// The resulting object carries `mode` forward, so a later `serialize` call emits
+    /// timestamps the same way.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON string cannot be parsed, if the top-level value is
+    /// not an object, or if an epoch value is out of range.
+    pub fn deserialize_with_mode(json_str: &str, mode: TimestampMode) -> Result<Self, serde_json::Error> {
+        let value: Value = serde_json::from_str(json_str)?;
+        let map = match value {
+            Value::Object(map) => map,
+            _ => return Err(serde::de::Error::custom("Expected a JSON object")),
+        };
+
+        let entries = map
+            .into_iter()
+            .map(|(k, v)| Ok((k, Entry::from_value_with_mode(v, mode)?)))
+            .collect::<Result<Map<_, _>, _>>()?;
+
+        Ok(JSON {
+            entries,
+            timestamp_mode: mode,
+            timestamp_seconds_format: SecondsFormat::AutoSi,
+            timestamp_use_z: false,
+        })
     }
 
-    /// Serializes the `JSON` object into a JSON string.
+    /// Serializes the `JSON` object into a JSON string, writing `Entry::Timestamp`
+    /// values through this object's `TimestampMode`, `SecondsFormat` and `use_z` setting.
     ///
     /// # Errors
     ///
     /// Returns an error if serialization fails.
     pub fn serialize(&self) -> Result<String, serde_json::Error> {
-        serde_json::to_string(&self.entries)
+        let value = Value::Object(
+            self.entries
+                .iter()
+                .map(|(k, v)| {
+                    (
+                        k.clone(),
+                        v.to_value_with_mode(
+                            self.timestamp_mode,
+                            self.timestamp_seconds_format,
+                            self.timestamp_use_z,
+                        ),
+                    )
+                })
+                .collect(),
+        );
+        serde_json::to_string(&value)
+    }
+
+    /// Deserializes a JSON string into a `JSON` object, binding each declared field in
+    /// `schema` to the `Entry` variant its `Types` mandates instead of guessing from shape.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON string cannot be parsed, if the top-level value is
+    /// not an object, or if a declared field's value does not match its `Types`.
+    pub fn deserialize_with_schema(json_str: &str, schema: &Schema) -> Result<Self, serde_json::Error> {
+        let value: Value = serde_json::from_str(json_str)?;
+        let map = match value {
+            Value::Object(map) => map,
+            _ => return Err(serde::de::Error::custom("Expected a JSON object")),
+        };
+
+        let entries = map
+            .into_iter()
+            .map(|(key, v)| {
+                let entry = match schema.type_of(&key) {
+                    Some(ty) => Entry::from_value_typed(v, ty, schema.schema_of(&key))?,
+                    None => serde_json::from_value(v).map_err(serde::de::Error::custom)?,
+                };
+                Ok((key, entry))
+            })
+            .collect::<Result<Map<_, _>, _>>()?;
+
+        Ok(JSON {
+            entries,
+            timestamp_mode: TimestampMode::default(),
+            timestamp_seconds_format: SecondsFormat::AutoSi,
+            timestamp_use_z: false,
+        })
+    }
+
+    /// Checks that every field declared in `schema` is present with an `Entry` value
+    /// consistent with its declared `Types`, recursing into nested schemas.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error describing the first field that violates `schema`.
+    pub fn validate(&self, schema: &Schema) -> Result<(), serde_json::Error> {
+        Self::validate_entries(&self.entries, schema)
+    }
+
+    fn validate_entries(entries: &Map<String, Entry>, schema: &Schema) -> Result<(), serde_json::Error> {
+        for (key, ty) in &schema.fields {
+            let Some(entry) = entries.get(key) else {
+                return Err(serde::de::Error::custom(format!(
+                    "Missing required field '{}' of declared type {}",
+                    key, ty
+                )));
+            };
+            if !entry.matches_type(ty) {
+                return Err(serde::de::Error::custom(format!(
+                    "Field '{}' does not match declared type {}",
+                    key, ty
+                )));
+            }
+            if let (Entry::Object(nested_entries), Some(sub_schema)) = (entry, schema.nested.get(key)) {
+                Self::validate_entries(nested_entries, sub_schema)?;
+            }
+        }
+        Ok(())
     }
 
     /// Retrieves a reference to an entry by key.
@@ -0,0 +1,28 @@
+use msfx::{Entry, JSON};
+
+fn main() {
+    let json_data = r#"
+        {
+            "age": 42,
+            "population": 8000000000,
+            "ratio": 0.5
+        }
+    "#;
+
+    let json_obj = JSON::deserialize(json_data).unwrap();
+
+    match json_obj.get("age") {
+        Some(Entry::Integer(n)) => println!("age fits Entry::Integer: {}", n),
+        other => panic!("Expected Entry::Integer, got {:?}", other),
+    }
+
+    match json_obj.get("population") {
+        Some(Entry::Long(n)) => println!("population exceeds i32 range, kept exact as Entry::Long: {}", n),
+        other => panic!("Expected Entry::Long, got {:?}", other),
+    }
+
+    match json_obj.get("ratio") {
+        Some(Entry::Float(f)) => println!("ratio is fractional, Entry::Float: {}", f),
+        other => panic!("Expected Entry::Float, got {:?}", other),
+    }
+}
@@ -0,0 +1,22 @@
+use msfx::{Entry, JSON};
+
+fn main() {
+    let json_data = r#"
+        {
+            "space_separated": "2024-11-02 14:30:00Z",
+            "bare_naive": "2024-11-02T14:30:00"
+        }
+    "#;
+
+    let json_obj = JSON::deserialize(json_data).unwrap();
+
+    match json_obj.get("space_separated") {
+        Some(Entry::Timestamp(ts)) => println!("Space-separated spelling parsed: {}", ts),
+        other => panic!("Expected Entry::Timestamp, got {:?}", other),
+    }
+
+    match json_obj.get("bare_naive") {
+        Some(Entry::Timestamp(ts)) => println!("Offset-less datetime treated as UTC: {}", ts),
+        other => panic!("Expected Entry::Timestamp, got {:?}", other),
+    }
+}
@@ -0,0 +1,29 @@
+use msfx::{Entry, Schema, Types, JSON};
+
+fn main() {
+    let schema = Schema::new()
+        .field("username", Types::VARCHAR)
+        .field("created_date", Types::DATE)
+        .field("last_login", Types::TIMESTAMP)
+        .field("file_data", Types::VARBINARY);
+
+    let json_data = r#"
+        {
+            "username": "user123",
+            "created_date": "2024-11-02",
+            "last_login": "2024-11-02T14:30:00Z",
+            "file_data": "SGVsbG8gd29ybGQ="
+        }
+    "#;
+
+    let json_obj = JSON::deserialize_with_schema(json_data, &schema).unwrap();
+
+    // Declared VARCHAR, "username" is kept as a string without any base64 attempt.
+    match json_obj.get("username") {
+        Some(Entry::String(s)) => println!("Username stayed a string: {}", s),
+        other => panic!("Expected Entry::String, got {:?}", other),
+    }
+
+    json_obj.validate(&schema).expect("document matches schema");
+    println!("Document validated against schema");
+}
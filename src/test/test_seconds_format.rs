@@ -0,0 +1,18 @@
+use chrono::SecondsFormat;
+use msfx::{Entry, TimestampMode, JSON};
+
+fn main() {
+    let mut json_obj = JSON::new()
+        .with_timestamp_mode(TimestampMode::Rfc3339)
+        .with_seconds_format(SecondsFormat::Millis)
+        .with_use_z(true);
+
+    json_obj.set(
+        "last_login".to_string(),
+        Entry::Timestamp("2024-11-02T14:30:00.123456789Z".parse().unwrap()),
+    );
+
+    let serialized = json_obj.serialize().unwrap();
+    println!("Serialized with millis precision and trailing Z: {}", serialized);
+    assert!(serialized.contains("2024-11-02T14:30:00.123Z"));
+}
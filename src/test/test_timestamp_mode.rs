@@ -0,0 +1,16 @@
+use msfx::{Entry, TimestampMode, JSON};
+
+fn main() {
+    let json_data = r#"{ "created_at": 1730561400 }"#;
+
+    let json_obj = JSON::deserialize_with_mode(json_data, TimestampMode::EpochSeconds).unwrap();
+
+    match json_obj.get("created_at") {
+        Some(Entry::Timestamp(ts)) => println!("Parsed epoch seconds into timestamp: {}", ts),
+        other => panic!("Expected Entry::Timestamp, got {:?}", other),
+    }
+
+    let serialized = json_obj.serialize().unwrap();
+    println!("Re-serialized with the same epoch mode: {}", serialized);
+    assert!(serialized.contains("1730561400"));
+}